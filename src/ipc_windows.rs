@@ -1,6 +1,6 @@
 use crate::discord_ipc::DiscordIpc;
 use serde_json::json;
-use std::{error::Error, path::PathBuf};
+use std::{error::Error, path::PathBuf, time::Duration};
 use windows::{
     core::PCWSTR,
     Win32::{
@@ -11,18 +11,120 @@ use windows::{
             ReadFile,
             WriteFile,
             FILE_ATTRIBUTE_NORMAL,
+            FILE_FLAG_OVERLAPPED,
             FILE_SHARE_READ,
             FILE_SHARE_WRITE,
             OPEN_EXISTING,
         },
+        System::{
+            Threading::{CreateEventW, WaitForSingleObject, INFINITE},
+            IO::{CancelIoEx, GetOverlappedResult, OVERLAPPED},
+        },
     },
 };
-use windows::Win32::Foundation::{GENERIC_READ, GENERIC_WRITE};
+use windows::Win32::Foundation::{
+    ERROR_IO_PENDING, GENERIC_READ, GENERIC_WRITE, WAIT_OBJECT_0, WAIT_TIMEOUT,
+};
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
-#[allow(dead_code)]
+/// Returned in place of the usual I/O error when a read or write exceeds its
+/// configured [`DiscordIpcClient::with_read_timeout`] /
+/// [`DiscordIpcClient::with_write_timeout`].
+#[derive(Debug)]
+pub struct IpcTimeoutError;
+
+impl std::fmt::Display for IpcTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Discord IPC operation timed out")
+    }
+}
+
+impl Error for IpcTimeoutError {}
+
+/// Connection lifecycle states reported through
+/// [`DiscordIpcClient::on_state_change`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// `connect_ipc` succeeded and the pipe is usable.
+    Connected,
+    /// The pipe was lost and no reconnect attempt is currently in flight.
+    Disconnected,
+    /// `ensure_connected` is sleeping/retrying after a lost connection.
+    Reconnecting,
+}
+
+/// Governs how [`DiscordIpcClient::ensure_connected`] retries a dropped
+/// connection.
+///
+/// The default policy makes exactly one immediate attempt, matching the
+/// client's historical behavior of failing fast instead of retrying.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Maximum number of `connect_ipc` attempts per `ensure_connected` call.
+    pub max_attempts: u32,
+    /// Delay before the second attempt.
+    pub initial_delay: Duration,
+    /// Factor the delay is multiplied by after each failed attempt.
+    pub multiplier: f64,
+    /// Upper bound on the delay between attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize each delay by up to +/-50% to avoid thundering
+    /// herds when many clients reconnect at once.
+    pub jitter: bool,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_delay: Duration::ZERO,
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            jitter: false,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let scaled = self.initial_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = scaled.min(self.max_delay.as_secs_f64());
+        let mut delay = Duration::from_secs_f64(capped.max(0.0));
+
+        if self.jitter && !delay.is_zero() {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.subsec_nanos())
+                .unwrap_or(0);
+            let factor = 0.5 + (nanos % 1000) as f64 / 1000.0;
+            delay = Duration::from_secs_f64(delay.as_secs_f64() * factor);
+        }
+
+        delay
+    }
+}
+
+/// Returned by [`DiscordIpcClient::ensure_connected`] once the configured
+/// [`ReconnectPolicy`] has exhausted its attempts.
 #[derive(Debug)]
+pub struct DiscordIpcDisconnectedError {
+    source: Option<Box<dyn Error>>,
+}
+
+impl std::fmt::Display for DiscordIpcDisconnectedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not reconnect to the Discord IPC pipe")
+    }
+}
+
+impl Error for DiscordIpcDisconnectedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref()
+    }
+}
+
+#[allow(dead_code)]
 /// A wrapper struct for the functionality contained in the
 /// underlying [`DiscordIpc`](trait@DiscordIpc) trait.
 pub struct DiscordIpcClient {
@@ -30,6 +132,23 @@ pub struct DiscordIpcClient {
     pub client_id: String,
     connected: bool,
     pipe_handle: Option<HANDLE>,
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+    reconnect_policy: ReconnectPolicy,
+    on_state_change: Option<Box<dyn Fn(ConnectionState) + Send + Sync>>,
+}
+
+impl std::fmt::Debug for DiscordIpcClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiscordIpcClient")
+            .field("client_id", &self.client_id)
+            .field("connected", &self.connected)
+            .field("pipe_handle", &self.pipe_handle)
+            .field("read_timeout", &self.read_timeout)
+            .field("write_timeout", &self.write_timeout)
+            .field("reconnect_policy", &self.reconnect_policy)
+            .finish_non_exhaustive()
+    }
 }
 
 impl DiscordIpcClient {
@@ -44,25 +163,131 @@ impl DiscordIpcClient {
             client_id: client_id.to_string(),
             connected: false,
             pipe_handle: None,
+            read_timeout: None,
+            write_timeout: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            on_state_change: None,
         })
     }
 
+    /// Sets the timeout applied to each `ReadFile` that `read` issues. A hung
+    /// Discord pipe will be cancelled and reported as [`IpcTimeoutError`]
+    /// instead of blocking forever. A `read` that needs several `ReadFile`
+    /// calls to fill its buffer can take longer than `timeout` in total.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout applied to every `write` call. See
+    /// [`with_read_timeout`](Self::with_read_timeout).
+    pub fn with_write_timeout(mut self, timeout: Duration) -> Self {
+        self.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the policy `ensure_connected` follows when the pipe drops. See
+    /// [`ReconnectPolicy`].
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Registers a callback that `ensure_connected` invokes with each
+    /// [`ConnectionState`] it passes through while dropping, retrying and
+    /// re-establishing the connection, so a host app can reflect presence
+    /// status in its UI. Calling `connect_ipc` or `close` directly, and a
+    /// failed `read`, do not invoke it.
+    pub fn on_state_change(mut self, callback: impl Fn(ConnectionState) + Send + Sync + 'static) -> Self {
+        self.on_state_change = Some(Box::new(callback));
+        self
+    }
+
+    fn emit_state(&self, state: ConnectionState) {
+        if let Some(callback) = &self.on_state_change {
+            callback(state);
+        }
+    }
+
+    /// Performs a `ReadFile`/`WriteFile` through `OVERLAPPED` I/O, waiting up
+    /// to `timeout` for completion before cancelling it with `CancelIoEx`.
+    ///
+    /// `issue` performs the actual `ReadFile`/`WriteFile` call with the
+    /// given `OVERLAPPED` and returns the immediate result; this function
+    /// takes care of waiting on `ERROR_IO_PENDING` and cancelling on
+    /// timeout.
+    unsafe fn with_overlapped_timeout(
+        handle: HANDLE,
+        timeout: Option<Duration>,
+        issue: impl FnOnce(*mut OVERLAPPED) -> windows::core::Result<()>,
+    ) -> Result<u32> {
+        let event = CreateEventW(None, true, false, PCWSTR::null())?;
+        let mut overlapped = OVERLAPPED {
+            hEvent: event,
+            ..Default::default()
+        };
+
+        let outcome = match issue(&mut overlapped) {
+            Ok(_) => {
+                let mut bytes_transferred = 0u32;
+                GetOverlappedResult(handle, &overlapped, &mut bytes_transferred, false)
+                    .map(|_| bytes_transferred)
+                    .map_err(|e| e.into())
+            }
+            Err(e) if e.code() == windows::core::HRESULT::from_win32(ERROR_IO_PENDING.0) => {
+                let wait_ms = timeout.map(|d| d.as_millis() as u32).unwrap_or(INFINITE);
+
+                match WaitForSingleObject(event, wait_ms) {
+                    WAIT_OBJECT_0 => {
+                        let mut bytes_transferred = 0u32;
+                        GetOverlappedResult(handle, &overlapped, &mut bytes_transferred, false)
+                            .map(|_| bytes_transferred)
+                            .map_err(|e| e.into())
+                    }
+                    WAIT_TIMEOUT => {
+                        let _ = CancelIoEx(handle, Some(&overlapped));
+                        Self::await_cancellation(handle, &overlapped);
+                        Err(Box::new(IpcTimeoutError) as Box<dyn Error>)
+                    }
+                    _ => {
+                        let wait_err = windows::core::Error::from_win32();
+                        let _ = CancelIoEx(handle, Some(&overlapped));
+                        Self::await_cancellation(handle, &overlapped);
+                        Err(wait_err.into())
+                    }
+                }
+            }
+            Err(e) => Err(e.into()),
+        };
+
+        let _ = CloseHandle(event);
+        outcome
+    }
+
+    /// Blocks until a cancelled overlapped operation actually finishes.
+    ///
+    /// `CancelIoEx` only *requests* cancellation; the kernel may still write
+    /// into the buffer/`OVERLAPPED`/event for a while after it returns. We
+    /// must wait for the real completion before the caller drops them (or
+    /// we close `event`), or the kernel can touch freed stack memory.
+    unsafe fn await_cancellation(handle: HANDLE, overlapped: &OVERLAPPED) {
+        let mut discarded = 0u32;
+        let _ = GetOverlappedResult(handle, overlapped, &mut discarded, true);
+    }
+
     // Add a method to check if the pipe is still valid
     unsafe fn is_pipe_valid(&self) -> bool {
         if let Some(handle) = self.pipe_handle {
-            // Try to write 0 bytes to check if pipe is still connected
-            let mut bytes_written = 0;
-            match WriteFile(
-                handle,
-                Some(&[]),
-                Some(&mut bytes_written),
-                None,
-            ) {
-                Ok(_) => true,
-                Err(e) => {
-                    false
-                },
-            }
+            // Try to write 0 bytes to check if pipe is still connected. The
+            // handle is opened with FILE_FLAG_OVERLAPPED, so this still
+            // needs an OVERLAPPED even though it completes immediately; the
+            // byte count is None since `GetOverlappedResult` supplies the
+            // real count and the kernel would otherwise write into this
+            // stack frame after the closure has already returned.
+            Self::with_overlapped_timeout(handle, None, |overlapped| {
+                WriteFile(handle, Some(&[]), None, Some(overlapped))
+            })
+            .is_ok()
         } else {
             false
         }
@@ -79,7 +304,28 @@ impl DiscordIpcClient {
                 }
             }
             self.pipe_handle = None;
-            self.connect_ipc()?;
+            self.emit_state(ConnectionState::Disconnected);
+
+            let max_attempts = self.reconnect_policy.max_attempts.max(1);
+            let mut last_err: Option<Box<dyn Error>> = None;
+
+            for attempt in 0..max_attempts {
+                if attempt > 0 {
+                    self.emit_state(ConnectionState::Reconnecting);
+                    std::thread::sleep(self.reconnect_policy.delay_for_attempt(attempt - 1));
+                }
+
+                match self.connect_ipc() {
+                    Ok(()) => {
+                        self.emit_state(ConnectionState::Connected);
+                        return Ok(());
+                    }
+                    Err(e) => last_err = Some(e),
+                }
+            }
+
+            self.emit_state(ConnectionState::Disconnected);
+            return Err(Box::new(DiscordIpcDisconnectedError { source: last_err }));
         }
         Ok(())
     }
@@ -97,7 +343,7 @@ impl DiscordIpcClient {
             FILE_SHARE_READ | FILE_SHARE_WRITE,
             None,
             OPEN_EXISTING,
-            FILE_ATTRIBUTE_NORMAL,
+            FILE_ATTRIBUTE_NORMAL | FILE_FLAG_OVERLAPPED,
             HANDLE(std::ptr::null_mut()),
         )?;
 
@@ -120,6 +366,283 @@ impl Drop for DiscordIpcClient {
     }
 }
 
+/// Async counterpart of [`DiscordIpc`](trait@DiscordIpc), built on overlapped
+/// named pipe I/O so a stalled Discord process can't block the executor.
+///
+/// Requires the `async` feature. The synchronous [`DiscordIpcClient`] is
+/// unaffected and keeps working without it.
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::Result;
+    use std::path::PathBuf;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::windows::named_pipe::{ClientOptions, NamedPipeClient};
+
+    /// Async version of the [`DiscordIpc`](trait@super::DiscordIpc) trait.
+    ///
+    /// Mirrors the sync trait one-for-one so callers can swap between the
+    /// two without relearning the API.
+    #[async_trait::async_trait]
+    pub trait AsyncDiscordIpc {
+        /// Connects to the Discord IPC socket, trying pipes `0` through `9`.
+        async fn connect_ipc(&mut self) -> Result<()>;
+
+        /// Writes `data` to the Discord IPC socket.
+        async fn write(&mut self, data: &[u8]) -> Result<()>;
+
+        /// Reads an incoming IPC message into `buffer`.
+        async fn read(&mut self, buffer: &mut [u8]) -> Result<()>;
+
+        /// Closes the Discord IPC connection.
+        async fn close(&mut self) -> Result<()>;
+
+        /// Returns the client ID of this `AsyncDiscordIpcClient`.
+        fn get_client_id(&self) -> &String;
+    }
+
+    /// A wrapper struct for the functionality contained in the underlying
+    /// [`AsyncDiscordIpc`](trait@AsyncDiscordIpc) trait.
+    #[allow(dead_code)]
+    pub struct AsyncDiscordIpcClient {
+        /// Client ID of the IPC client.
+        pub client_id: String,
+        connected: bool,
+        pipe: Option<NamedPipeClient>,
+    }
+
+    impl AsyncDiscordIpcClient {
+        /// Creates a new `AsyncDiscordIpcClient`.
+        ///
+        /// # Examples
+        /// ```
+        /// let ipc_client = AsyncDiscordIpcClient::new("<some client id>")?;
+        /// ```
+        pub fn new(client_id: &str) -> Result<Self> {
+            Ok(Self {
+                client_id: client_id.to_string(),
+                connected: false,
+                pipe: None,
+            })
+        }
+
+        async fn ensure_connected(&mut self) -> Result<()> {
+            if !self.connected || self.pipe.is_none() {
+                self.connected = false;
+                self.pipe = None;
+                self.connect_ipc().await?;
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncDiscordIpc for AsyncDiscordIpcClient {
+        async fn connect_ipc(&mut self) -> Result<()> {
+            for i in 0..10 {
+                let path = PathBuf::from(format!(r"\\?\pipe\discord-ipc-{}", i));
+
+                match ClientOptions::new().open(&path) {
+                    Ok(pipe) => {
+                        self.pipe = Some(pipe);
+                        self.connected = true;
+                        return Ok(());
+                    }
+                    Err(_) => continue,
+                }
+            }
+
+            Err("Could not connect to Discord IPC pipe".into())
+        }
+
+        async fn write(&mut self, data: &[u8]) -> Result<()> {
+            self.ensure_connected().await?;
+
+            let pipe = self
+                .pipe
+                .as_mut()
+                .ok_or("Pipe handle not initialized")?;
+
+            match pipe.write_all(data).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    // The pipe broke or Discord went away; let the next
+                    // `ensure_connected` call rebuild it.
+                    self.connected = false;
+                    Err(e.into())
+                }
+            }
+        }
+
+        async fn read(&mut self, buffer: &mut [u8]) -> Result<()> {
+            let pipe = self
+                .pipe
+                .as_mut()
+                .ok_or("Pipe handle not initialized")?;
+
+            match pipe.read_exact(buffer).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    self.connected = false;
+                    Err(e.into())
+                }
+            }
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            self.connected = false;
+            self.pipe = None;
+            Ok(())
+        }
+
+        fn get_client_id(&self) -> &String {
+            &self.client_id
+        }
+    }
+}
+
+/// Maximum payload length accepted by [`DiscordIpcFrame::read_frame`],
+/// guarding against unbounded allocation on a corrupt stream.
+pub const MAX_FRAME_LEN: u32 = 64 * 1024;
+
+/// Discord IPC wire framing on top of [`DiscordIpc`](trait@DiscordIpc)'s raw
+/// `read`/`write`.
+///
+/// A frame is a little-endian `u32` opcode, a little-endian `u32` payload
+/// length, then exactly that many UTF-8 JSON bytes. Blanket-implemented for
+/// every `DiscordIpc` so `close()` and handshake code don't have to
+/// hand-roll the header themselves.
+pub trait DiscordIpcFrame: DiscordIpc {
+    /// Writes `payload` as a single IPC frame with the given `opcode`.
+    fn write_frame(&mut self, opcode: u32, payload: &serde_json::Value) -> Result<()> {
+        let payload_bytes = serde_json::to_vec(payload)?;
+
+        let mut frame = Vec::with_capacity(8 + payload_bytes.len());
+        frame.extend_from_slice(&opcode.to_le_bytes());
+        frame.extend_from_slice(&(payload_bytes.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&payload_bytes);
+
+        self.write(&frame)
+    }
+
+    /// Reads a single IPC frame and parses its payload as JSON.
+    fn read_frame(&mut self) -> Result<(u32, serde_json::Value)> {
+        let mut header = [0u8; 8];
+        self.read(&mut header)?;
+
+        let opcode = u32::from_le_bytes(header[0..4].try_into().unwrap());
+        let payload_len = u32::from_le_bytes(header[4..8].try_into().unwrap());
+
+        if payload_len > MAX_FRAME_LEN {
+            return Err(format!(
+                "IPC frame payload of {} bytes exceeds the {} byte cap",
+                payload_len, MAX_FRAME_LEN
+            )
+            .into());
+        }
+
+        let mut payload = vec![0u8; payload_len as usize];
+        if payload_len > 0 {
+            self.read(&mut payload)?;
+        }
+
+        let value = serde_json::from_slice(&payload)?;
+        Ok((opcode, value))
+    }
+
+    /// Performs Discord's mandatory opcode-0 handshake and returns the
+    /// decoded `user` object from the `READY` event.
+    ///
+    /// Calls [`connect_ipc`](DiscordIpc::connect_ipc) first, so callers no
+    /// longer need to invoke it themselves before handshaking. Relies on
+    /// [`read_frame`](Self::read_frame) reading each frame to completion
+    /// (see `DiscordIpcClient::read`) so the reply is never parsed from a
+    /// short read.
+    fn connect(&mut self) -> Result<serde_json::Value> {
+        self.connect_ipc()?;
+
+        self.write_frame(0, &json!({ "v": 1, "client_id": self.get_client_id() }))?;
+
+        let (opcode, payload) = self.read_frame()?;
+
+        // A failed handshake (e.g. an unknown client_id) comes back as a
+        // CLOSE frame carrying `{code, message}` directly, with no
+        // `cmd`/`evt` of its own, so it must be keyed off the opcode rather
+        // than the `evt` field used for DISPATCH frames below.
+        if opcode == DISCORD_IPC_OPCODE_CLOSE {
+            return Err(Box::new(DiscordIpcHandshakeError::from_payload(&payload)));
+        }
+
+        let cmd = payload["cmd"].as_str().unwrap_or_default().to_string();
+        let evt = payload["evt"].as_str().map(str::to_string);
+        let data = payload["data"].clone();
+
+        if evt.as_deref() == Some("ERROR") {
+            return Err(Box::new(DiscordIpcHandshakeError::from_payload(&data)));
+        }
+
+        if cmd != "DISPATCH" || evt.as_deref() != Some("READY") {
+            return Err(format!(
+                "Unexpected handshake reply: cmd={}, evt={:?}",
+                cmd, evt
+            )
+            .into());
+        }
+
+        Ok(data["user"].clone())
+    }
+
+    /// Reads one event frame and splits it into its opcode, `cmd`, optional
+    /// `evt`, and `data` fields, so callers don't have to destructure the
+    /// raw JSON for every subscribed event.
+    fn recv_event(&mut self) -> Result<(u32, String, Option<String>, serde_json::Value)> {
+        let (opcode, mut payload) = self.read_frame()?;
+
+        let cmd = payload["cmd"].as_str().unwrap_or_default().to_string();
+        let evt = payload["evt"].as_str().map(str::to_string);
+        let data = payload["data"].take();
+
+        Ok((opcode, cmd, evt, data))
+    }
+}
+
+impl<T: DiscordIpc + ?Sized> DiscordIpcFrame for T {}
+
+/// The `CLOSE` opcode Discord uses both to terminate a connection (see
+/// [`DiscordIpc::close`]) and to report a failed handshake.
+const DISCORD_IPC_OPCODE_CLOSE: u32 = 2;
+
+/// Returned by [`DiscordIpcFrame::connect`] when Discord rejects the
+/// handshake, either via an `ERROR` event or a `CLOSE` frame.
+#[derive(Debug)]
+pub struct DiscordIpcHandshakeError {
+    pub code: Option<i64>,
+    pub message: String,
+}
+
+impl DiscordIpcHandshakeError {
+    fn from_payload(payload: &serde_json::Value) -> Self {
+        let code = payload.get("code").and_then(|c| c.as_i64());
+        let message = payload
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("Discord IPC handshake failed")
+            .to_string();
+
+        Self { code, message }
+    }
+}
+
+impl std::fmt::Display for DiscordIpcHandshakeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "Discord IPC handshake error {}: {}", code, self.message),
+            None => write!(f, "Discord IPC handshake error: {}", self.message),
+        }
+    }
+}
+
+impl Error for DiscordIpcHandshakeError {}
+
 impl DiscordIpc for DiscordIpcClient {
     fn connect_ipc(&mut self) -> Result<()> {
         for i in 0..10 {
@@ -145,34 +668,40 @@ impl DiscordIpc for DiscordIpcClient {
     fn write(&mut self, data: &[u8]) -> Result<()> {
         self.ensure_connected()?;
 
-
         let mut retries = 3;
 
         while retries > 0 {
             let handle = self.pipe_handle.ok_or("Pipe handle not initialized")?;
-            let mut bytes_written = 0;
 
-            unsafe {
-                return match WriteFile(
-                    handle,
-                    Some(data),
-                    Some(&mut bytes_written),
-                    None,
-                ) {
-                    Ok(_) => Ok(()),
-                    Err(e) => {
-                        if e.code() == windows::core::HRESULT::from_win32(0x800700E8) {
+            let result = unsafe {
+                // None for the byte count: `GetOverlappedResult` supplies
+                // the real count once the write completes, and the kernel
+                // would otherwise write into this closure's stack frame
+                // after it has already returned.
+                Self::with_overlapped_timeout(handle, self.write_timeout, |overlapped| {
+                    WriteFile(handle, Some(data), None, Some(overlapped))
+                })
+            };
+
+            return match result {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    if e.is::<IpcTimeoutError>() {
+                        self.connected = false;
+                        return Err(e);
+                    }
+
+                    if let Some(we) = e.downcast_ref::<windows::core::Error>() {
+                        if we.code() == windows::core::HRESULT::from_win32(0x800700E8) && retries > 1 {
                             // Pipe is being closed error
-                            if retries > 1 {
-                                self.connected = false;
-                                self.ensure_connected()?;
+                            self.connected = false;
+                            self.ensure_connected()?;
 
-                                retries -= 1;
-                                continue;
-                            }
+                            retries -= 1;
+                            continue;
                         }
-                        Err(e.into())
                     }
+                    Err(e)
                 }
             }
         }
@@ -182,21 +711,43 @@ impl DiscordIpc for DiscordIpcClient {
 
     fn read(&mut self, buffer: &mut [u8]) -> Result<()> {
         let handle = self.pipe_handle.ok_or("Pipe handle not initialized")?;
-        let mut bytes_read = 0;
 
-        unsafe {
-            ReadFile(
-                handle,
-                Some(buffer),
-                Some(&mut bytes_read),
-                None,
-            ).map_err(|e| e.into())
+        // A byte-mode pipe's ReadFile can return fewer bytes than requested,
+        // so honor the transferred count and keep going until `buffer` is
+        // completely filled instead of handing callers a partial read.
+        let mut filled = 0;
+
+        while filled < buffer.len() {
+            let result = unsafe {
+                // None for the byte count: `GetOverlappedResult` supplies
+                // the real count once the read completes, and the kernel
+                // would otherwise write into this closure's stack frame
+                // after it has already returned.
+                Self::with_overlapped_timeout(handle, self.read_timeout, |overlapped| {
+                    ReadFile(handle, Some(&mut buffer[filled..]), None, Some(overlapped))
+                })
+            };
+
+            match result {
+                Ok(0) => {
+                    self.connected = false;
+                    return Err("Discord IPC pipe closed mid-read".into());
+                }
+                Ok(bytes_read) => filled += bytes_read as usize,
+                Err(e) => {
+                    if e.is::<IpcTimeoutError>() {
+                        self.connected = false;
+                    }
+                    return Err(e);
+                }
+            }
         }
+
+        Ok(())
     }
 
     fn close(&mut self) -> Result<()> {
-        let data = json!({});
-        let _ = self.send(data, 2);
+        let _ = self.write_frame(DISCORD_IPC_OPCODE_CLOSE, &json!({}));
 
         if let Some(handle) = self.pipe_handle {
             unsafe {